@@ -1,4 +1,4 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
 use url::Url;
 use urlnorm::*;
 
@@ -15,10 +15,7 @@ pub fn normalize_benchmark(c: &mut Criterion) {
 }
 
 pub fn torture_test(c: &mut Criterion) {
-    let x = std::iter::repeat("A5.html")
-        .take(50000)
-        .collect::<String>()
-        .to_owned();
+    let x = "A5.html".repeat(50000);
     let mut url_input = "https://goooooooogle.com/hello/index.html/".to_owned();
     url_input.push_str(x.as_str());
     let url = Url::parse(&url_input).unwrap();
@@ -31,5 +28,81 @@ pub fn torture_test(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, normalize_benchmark, torture_test);
+pub fn compile_benchmark(c: &mut Criterion) {
+    c.bench_function("compile", |b| {
+        b.iter(|| Options::default().compile().unwrap())
+    });
+    c.bench_function("compile cached", |b| {
+        b.iter(|| Options::default().compile_cached().unwrap())
+    });
+}
+
+pub fn snapshot_benchmark(c: &mut Criterion) {
+    let url = Url::parse("http://content.usatoday.com/communities/sciencefair/post/2011/07/invasion-of-the-viking-women-unearthed/1?csp=34tech&utm_source=feedburner").expect("Failed to parse this URL");
+    let candidates = (0..100)
+        .map(|i| {
+            Url::parse(&format!(
+                "https://content.usatoday.com/communities/sciencefair/post/2011/07/{}/1?csp=34tech",
+                i
+            ))
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let norm = UrlNormalizer::default();
+    c.bench_function("compare 100 candidates", |b| {
+        b.iter(|| {
+            candidates
+                .iter()
+                .filter(|candidate| norm.are_same(&url, candidate))
+                .count()
+        })
+    });
+    c.bench_function("compare 100 candidates with snapshot", |b| {
+        b.iter(|| {
+            let snapshot = norm.snapshot(&url);
+            candidates
+                .iter()
+                .filter(|candidate| norm.are_same_snapshot(&snapshot, candidate))
+                .count()
+        })
+    });
+}
+
+pub fn token_capacity_benchmark(c: &mut Criterion) {
+    let query = (0..40)
+        .map(|i| format!("param{}=value{}", i, i))
+        .collect::<Vec<_>>()
+        .join("&");
+    let urls = (0..10)
+        .map(|i| Url::parse(&format!("http://api.example.com/v1/search/{}?{}", i, query)).unwrap())
+        .collect::<Vec<_>>();
+    let norm = UrlNormalizer::default();
+    let tuned = Options::default()
+        .with_token_capacity(96)
+        .compile()
+        .unwrap();
+    c.bench_function("normalize query-heavy urls", |b| {
+        b.iter(|| {
+            for url in &urls {
+                norm.compute_normalization_string(url);
+            }
+        })
+    });
+    c.bench_function("normalize query-heavy urls with tuned capacity", |b| {
+        b.iter(|| {
+            for url in &urls {
+                tuned.compute_normalization_string(url);
+            }
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    normalize_benchmark,
+    torture_test,
+    compile_benchmark,
+    snapshot_benchmark,
+    token_capacity_benchmark
+);
 criterion_main!(benches);
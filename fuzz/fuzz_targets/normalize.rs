@@ -0,0 +1,52 @@
+#![no_main]
+use std::sync::LazyLock;
+
+use libfuzzer_sys::fuzz_target;
+use url::Url;
+use urlnorm::UrlNormalizer;
+
+static NORM: LazyLock<UrlNormalizer> = LazyLock::new(UrlNormalizer::default);
+
+fuzz_target!(|data: &str| {
+    let Ok(url) = Url::parse(data) else {
+        return;
+    };
+
+    // Must never panic
+    let normalized = NORM.compute_normalization_string(&url);
+    let canonical = NORM.to_canonical_url_string(&url);
+    NORM.path_normalization_string(&url);
+
+    // Reflexivity
+    assert!(NORM.are_same(&url, &url), "{} != itself", url);
+    assert!(NORM.same_path(&url, &url), "{} != itself", url);
+
+    // The URL parser itself isn't idempotent for some edge cases (ie: file URLs with a host and a drive letter), so we can
+    // only check our own idempotence if the URL survives a round-trip
+    if Url::parse(url.as_str()).as_ref() != Ok(&url) {
+        return;
+    }
+
+    // Idempotence: normalizing the canonical form must give the same result
+    if let Ok(canonical) = Url::parse(&canonical) {
+        assert_eq!(
+            normalized,
+            NORM.compute_normalization_string(&canonical),
+            "{} normalized differently to its canonical form {}",
+            url,
+            canonical
+        );
+        assert_eq!(NORM.to_canonical_url_string(&canonical), canonical.as_str());
+    }
+
+    // Reversible normalization is idempotent
+    let reversible = NORM.normalize_reversible(&url);
+    if let Ok(reversible_url) = Url::parse(&reversible) {
+        assert_eq!(
+            NORM.normalize_reversible(&reversible_url),
+            reversible,
+            "{} reversible normalization is not idempotent",
+            url
+        );
+    }
+});